@@ -1,20 +1,20 @@
-use crossterm::event::KeyEvent;
+use crossterm::event::{Event, KeyEvent, MouseEvent};
 use tui::{
     backend::Backend,
     Frame,
 };
 
 /// A trait which should be implemented by your app struct.
-/// 
+///
 /// This trait tells the [`UI`](crate::ui::UI) how to draw your app to the screen,
 /// what to do when input is received, and how to find the variable
 /// containing the open state of your program.
-/// 
+///
 /// You can also implement the [`Ticked`] trait on your app which
 /// allows you to run the [`UI`](crate::ui::UI) in ticked mode (using `run_ticked`).
-/// 
+///
 /// # Examples
-/// 
+///
 /// ```
 /// use tui_wrapper::{ui::UI, app::App};
 /// use tui::{
@@ -23,11 +23,11 @@ use tui::{
 ///     Frame,
 /// };
 /// use crossterm::event::{KeyEvent, KeyCode};
-/// 
+///
 /// struct HappyTitleApp {
 ///     is_closed: bool,
 /// }
-/// 
+///
 /// impl App for HappyTitleApp {
 ///     fn draw(&mut self, f: &mut Frame<impl Backend>) {
 ///         let block = Block::default()
@@ -35,51 +35,85 @@ use tui::{
 ///             .border_type(BorderType::Thick);
 ///         f.render_widget(block, f.size());
 ///     }
-/// 
-///     fn on_input_received(&mut self, event: KeyEvent) {
+///
+///     fn on_key(&mut self, event: KeyEvent) {
 ///         if event.code == KeyCode::Char('q') {
 ///             self.is_closed = true;
 ///         }
 ///     }
-/// 
+///
 ///     fn is_closed(&self) -> bool {
 ///         self.is_closed
 ///     }
 /// }
-/// 
+///
 /// let mut ui = UI::new(HappyTitleApp { is_closed: false }).unwrap();
 /// ui.run().expect("There was an issue with initializing the terminal!");
 /// ui.destroy_app().expect("There was an error uninitializing the terminal!");
 /// ```
 pub trait App {
     /// The funcion called by the [`UI`](crate::ui::UI) every frame of the application.
-    /// 
+    ///
     /// Use the mutable reference to the [`Frame`] provided by this
     /// function to draw [`tui`] widgets to the screen.
     fn draw(&mut self, f: &mut Frame<impl Backend>);
+
     /// The function called by the [`UI`](crate::ui::UI) every time an input event
     /// is received.
-    fn on_input_received(&mut self, event: KeyEvent);
+    ///
+    /// The default implementation fans this out to whichever of
+    /// [`App::on_key`], [`App::on_mouse`], [`App::on_resize`] and
+    /// [`App::on_paste`] matches the event, so most apps should
+    /// implement those instead and never need to override this.
+    /// Override it yourself if you need the raw [`Event`].
+    fn on_input_received(&mut self, event: Event) {
+        match event {
+            Event::Key(key) => self.on_key(key),
+            Event::Mouse(mouse) => self.on_mouse(mouse),
+            Event::Resize(width, height) => self.on_resize(width, height),
+            Event::Paste(text) => self.on_paste(text),
+            Event::FocusGained | Event::FocusLost => {}
+        }
+    }
+
+    /// Called by the default [`App::on_input_received`] when a key is
+    /// pressed. Does nothing by default.
+    fn on_key(&mut self, _event: KeyEvent) {}
+
+    /// Called by the default [`App::on_input_received`] when a mouse
+    /// event is received. Does nothing by default; the [`UI`](crate::ui::UI)
+    /// enables mouse capture for you, so this only fires if you want it to.
+    fn on_mouse(&mut self, _event: MouseEvent) {}
+
+    /// Called by the default [`App::on_input_received`] when the
+    /// terminal is resized, with the new width and height. Does
+    /// nothing by default.
+    fn on_resize(&mut self, _width: u16, _height: u16) {}
+
+    /// Called by the default [`App::on_input_received`] when text is
+    /// pasted into the terminal. Does nothing by default.
+    fn on_paste(&mut self, _text: String) {}
+
     /// A getter function for anywhere in your code which indicates
     /// if your app is in a state where it should close. You most
     /// likely will want to use this as a getter for a field in your
-    /// application struct, rather than to a variable in the main 
+    /// application struct, rather than to a variable in the main
     /// body of your program.
     fn is_closed(&self) -> bool;
 }
 
 /// A secondary trait that can be implemented by your app struct.
-/// 
+///
 /// This trait allows you to run code at a fixed rate whilst inputs
 /// are not being received.
-/// 
+///
 /// To use this trait correctly, you must implement it along with
-/// the App trait and then use the `new_ticked` & `run_ticked` 
+/// the App trait and then use the `new_ticked` & `run_ticked`
 /// associated functions as opposed to the standard `new` and `run`
 /// functions.
-/// 
+///
 /// # Examples
-/// 
+///
 /// ```
 /// use tui_wrapper::{ui::UI, app::{App, Ticked}};
 /// use tui::{
@@ -88,12 +122,12 @@ pub trait App {
 ///     Frame,
 /// };
 /// use crossterm::event::{KeyEvent, KeyCode};
-/// 
+///
 /// struct HappyTitleApp {
 ///     is_closed: bool,
 ///     ticks: u128,
 /// }
-/// 
+///
 /// impl App for HappyTitleApp {
 ///     fn draw(&mut self, f: &mut Frame<impl Backend>) {
 ///         let block = Block::default()
@@ -101,24 +135,24 @@ pub trait App {
 ///             .border_type(BorderType::Thick);
 ///         f.render_widget(block, f.size());
 ///     }
-/// 
-///     fn on_input_received(&mut self, event: KeyEvent) {
+///
+///     fn on_key(&mut self, event: KeyEvent) {
 ///         if event.code == KeyCode::Char('q') {
 ///             self.is_closed = true;
 ///         }
 ///     }
-/// 
+///
 ///     fn is_closed(&self) -> bool {
 ///         self.is_closed
 ///     }
 /// }
-/// 
+///
 /// impl Ticked for HappyTitleApp {
 ///     fn on_tick(&mut self) {
 ///         self.ticks += 1;
 ///     }
 /// }
-/// 
+///
 /// let mut ui = UI::new(HappyTitleApp { is_closed: false, ticks: 0 }).unwrap();
 /// ui.run().expect("There was an issue with initializing the terminal!");
 /// ui.destroy_app().expect("There was an error uninitializing the terminal!");
@@ -126,4 +160,4 @@ pub trait App {
 pub trait Ticked {
     /// A function called at a fixed interval by [`UI`](crate::ui::UI)s which are [`Ticked`]
     fn on_tick(&mut self);
-}
\ No newline at end of file
+}
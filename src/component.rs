@@ -0,0 +1,49 @@
+use crossterm::event::Event;
+use tui::{backend::Backend, layout::Rect, Frame};
+
+/// A message a [`Component`] can return from `handle_event` or
+/// `update`.
+///
+/// The owning [`UI`](crate::ui::UI) feeds every `Action` it receives
+/// back into the `update` method of every registered component (so
+/// components can react to one another without being wired together
+/// directly), and also acts on [`Action::Quit`] and [`Action::Render`]
+/// itself.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Action {
+    /// Ask the [`UI`](crate::ui::UI) to close the app.
+    Quit,
+    /// Ask the [`UI`](crate::ui::UI) to redraw on the next cycle.
+    Render,
+    /// An application-defined message, passed through untouched to
+    /// every component's `update`.
+    Custom(String),
+}
+
+/// An independently-updating panel of a multi-panel app.
+///
+/// Implement this, alongside [`App`](crate::app::App), to split a
+/// screen into components which each own their own drawing and
+/// event handling and talk to one another through [`Action`]s,
+/// rather than by hand-wiring shared state between widgets. Register
+/// components with [`UI::add_component`](crate::ui::UI::add_component);
+/// the [`UI`](crate::ui::UI) then draws each one every frame and
+/// routes every input event and returned [`Action`] to them.
+pub trait Component<B: Backend> {
+    /// Draws this component into `area` of the frame.
+    fn draw(&mut self, f: &mut Frame<B>, area: Rect);
+
+    /// Called with every input event the [`UI`](crate::ui::UI)
+    /// receives. Return an [`Action`] to have it fed back into every
+    /// registered component's `update`. Does nothing by default.
+    fn handle_event(&mut self, _event: Event) -> Option<Action> {
+        None
+    }
+
+    /// Called with every [`Action`] returned by any registered
+    /// component (including this one). Return a further [`Action`]
+    /// to keep the chain going. Does nothing by default.
+    fn update(&mut self, _action: Action) -> Option<Action> {
+        None
+    }
+}
@@ -15,6 +15,11 @@
 //! crossterm = "0.25"
 //! tui = "0.19.0"
 //! ```
+//!
+//! Enabling the `async` feature additionally pulls in `tokio` and
+//! `futures`, and adds the `run_async`/`run_ticked_async` methods on
+//! [`UI`](crate::ui::UI) for driving an app from an async runtime
+//! instead of blocking on terminal input.
 
 /// This module contains the [`UI`](crate::ui::UI) struct.
 pub mod ui;
@@ -24,4 +29,42 @@ pub mod ui;
 /// [`ui`](crate::ui) module to create applications.
 pub mod app;
 
-mod tests;
\ No newline at end of file
+/// This module contains the optional [`Component`](crate::component::Component)
+/// trait and [`Action`](crate::component::Action) enum, which let you
+/// split an [`App`](crate::app::App) into independently-updating
+/// panels registered with [`UI::add_component`](crate::ui::UI::add_component).
+pub mod component;
+
+mod tests;
+
+use crossterm::{
+    event::{DisableMouseCapture, EnableMouseCapture},
+    execute,
+    terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
+};
+use std::io::{self, Stdout};
+use tui::{backend::CrosstermBackend, Terminal};
+
+/// Enables raw mode, enters the alternate screen, and enables mouse
+/// capture, returning a ready-to-use [`Terminal`]. The opinionated
+/// counterpart to [`restore`], for callers who want a set-up terminal
+/// without the full [`UI`](crate::ui::UI)/[`App`](crate::app::App) wrapper.
+pub fn init() -> io::Result<Terminal<CrosstermBackend<Stdout>>> {
+    enable_raw_mode()?;
+    let mut stdout = io::stdout();
+    execute!(stdout, EnableMouseCapture, EnterAlternateScreen)?;
+    Terminal::new(CrosstermBackend::new(stdout))
+}
+
+/// Undoes whatever [`init`] did: disables raw mode, disables mouse
+/// capture, leaves the alternate screen, and shows the cursor again.
+pub fn restore() -> io::Result<()> {
+    disable_raw_mode()?;
+    execute!(
+        io::stdout(),
+        DisableMouseCapture,
+        LeaveAlternateScreen,
+        crossterm::cursor::Show
+    )?;
+    Ok(())
+}
\ No newline at end of file
@@ -24,7 +24,7 @@ mod tests {
                 
             }
 
-            fn on_input_received(&mut self, event: KeyEvent) {
+            fn on_key(&mut self, event: KeyEvent) {
                 if event.code == KeyCode::Char('q') {
                     self.should_close = true;
                 }
@@ -47,4 +47,174 @@ mod tests {
         bad_ui.run_ticked().expect("An error was encountered during initialization of the terminal.");
         bad_ui.destroy_app().expect("An error was encountered uninitializing the terminal.");
     }
+
+    #[test]
+    fn action_render_forces_a_redraw() {
+        use crate::ui::UI;
+        use crate::app::App;
+        use crate::component::{Action, Component};
+        use tui::{backend::{Backend, TestBackend}, layout::Rect, Frame};
+
+        struct EmptyApp;
+
+        impl App for EmptyApp {
+            fn draw(&mut self, _f: &mut Frame<impl Backend>) {}
+
+            fn is_closed(&self) -> bool {
+                true
+            }
+        }
+
+        struct EmptyComponent;
+
+        impl<B: Backend> Component<B> for EmptyComponent {
+            fn draw(&mut self, _f: &mut Frame<B>, _area: Rect) {}
+        }
+
+        let mut ui = UI::<_, TestBackend>::new(EmptyApp).unwrap();
+        ui.add_component(Box::new(EmptyComponent));
+        assert!(!ui.render_requested());
+        ui.dispatch_action(Action::Render);
+        assert!(ui.render_requested());
+        ui.destroy_app().expect("An error was encountered uninitializing the terminal.");
+    }
+
+    #[test]
+    fn runs_headlessly_against_a_test_backend() {
+        use crate::ui::UI;
+        use crate::app::App;
+        use tui::{backend::{Backend, TestBackend}, Frame};
+
+        struct ClosedApp;
+
+        impl App for ClosedApp {
+            fn draw(&mut self, _f: &mut Frame<impl Backend>) {}
+
+            fn is_closed(&self) -> bool {
+                true
+            }
+        }
+
+        let mut ui = UI::<_, TestBackend>::new(ClosedApp).unwrap();
+        ui.run().expect("An error was encountered running against the test backend.");
+        ui.destroy_app().expect("An error was encountered uninitializing the test backend.");
+    }
+
+    #[test]
+    fn run_ticked_draws_the_first_frame_immediately() {
+        use crate::ui::UI;
+        use crate::app::{App, Ticked};
+        use tui::{backend::{Backend, TestBackend}, Frame};
+        use std::time::Duration;
+
+        struct ClosedApp {
+            draws: u32,
+        }
+
+        impl App for ClosedApp {
+            fn draw(&mut self, _f: &mut Frame<impl Backend>) {
+                self.draws += 1;
+            }
+
+            fn is_closed(&self) -> bool {
+                true
+            }
+        }
+
+        impl Ticked for ClosedApp {
+            fn on_tick(&mut self) {}
+        }
+
+        let mut ui = UI::<_, TestBackend>::new_ticked(
+            ClosedApp { draws: 0 },
+            Duration::from_millis(250),
+            Duration::from_millis(1000),
+        )
+        .unwrap();
+        ui.run_ticked().expect("An error was encountered running in ticked mode.");
+        assert_eq!(ui.app().draws, 1, "run_ticked should draw once immediately, even with is_closed() true from the start");
+        ui.destroy_app().expect("An error was encountered uninitializing the test backend.");
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn run_async_and_run_ticked_async_construct_and_return() {
+        use crate::ui::UI;
+        use crate::app::{App, Ticked};
+        use tui::{backend::{Backend, TestBackend}, Frame};
+        use std::time::Duration;
+
+        struct ClosedApp;
+
+        impl App for ClosedApp {
+            fn draw(&mut self, _f: &mut Frame<impl Backend>) {}
+
+            fn is_closed(&self) -> bool {
+                true
+            }
+        }
+
+        impl Ticked for ClosedApp {
+            fn on_tick(&mut self) {}
+        }
+
+        let mut ui = UI::<_, TestBackend>::new(ClosedApp).unwrap();
+        ui.run_async().await.expect("An error was encountered running async.");
+        ui.destroy_app().expect("An error was encountered uninitializing the test backend.");
+
+        let mut ticked_ui = UI::<_, TestBackend>::new_ticked(
+            ClosedApp,
+            Duration::from_millis(250),
+            Duration::from_millis(1000),
+        )
+        .unwrap();
+        ticked_ui
+            .run_ticked_async()
+            .await
+            .expect("An error was encountered running ticked async.");
+        ticked_ui.destroy_app().expect("An error was encountered uninitializing the test backend.");
+    }
+
+    #[test]
+    fn destroy_app_is_idempotent() {
+        use crate::ui::UI;
+        use crate::app::App;
+        use tui::{backend::{Backend, TestBackend}, Frame};
+
+        struct ClosedApp;
+
+        impl App for ClosedApp {
+            fn draw(&mut self, _f: &mut Frame<impl Backend>) {}
+
+            fn is_closed(&self) -> bool {
+                true
+            }
+        }
+
+        let mut ui = UI::<_, TestBackend>::new(ClosedApp).unwrap();
+        ui.destroy_app().expect("The first destroy_app call should succeed.");
+        ui.destroy_app().expect("Calling destroy_app a second time should be a no-op, not an error.");
+    }
+
+    #[test]
+    fn new_with_options_supports_an_inline_viewport() {
+        use crate::ui::UI;
+        use crate::app::App;
+        use tui::{backend::{Backend, TestBackend}, Frame, Viewport};
+
+        struct ClosedApp;
+
+        impl App for ClosedApp {
+            fn draw(&mut self, _f: &mut Frame<impl Backend>) {}
+
+            fn is_closed(&self) -> bool {
+                true
+            }
+        }
+
+        let mut ui =
+            UI::<_, TestBackend>::new_with_options(ClosedApp, Viewport::Inline(3)).unwrap();
+        ui.run().expect("An error was encountered running an inline-viewport UI.");
+        ui.destroy_app().expect("An error was encountered uninitializing the test backend.");
+    }
 }
\ No newline at end of file
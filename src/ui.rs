@@ -1,33 +1,150 @@
 use tui::{
-    backend::CrosstermBackend,
-    Terminal,
+    backend::{Backend, CrosstermBackend, TestBackend},
+    Terminal, TerminalOptions, Viewport,
 };
 use crossterm::{
-    event::{self, DisableMouseCapture, EnableMouseCapture},
+    event::{self, DisableBracketedPaste, DisableMouseCapture, EnableBracketedPaste, EnableMouseCapture},
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
 use std::io::{self, Stdout};
+use std::panic::{self, PanicInfo};
+use std::sync::Arc;
 use std::time::{Duration, Instant};
 use crate::app::{App, Ticked};
+use crate::component::{Action, Component};
+
+#[cfg(feature = "async")]
+use crossterm::event::{Event as CEvent, EventStream};
+#[cfg(feature = "async")]
+use futures::StreamExt;
+#[cfg(feature = "async")]
+use tokio::sync::mpsc;
+
+/// A [`tui`] [`Backend`] that knows how to put the terminal it owns
+/// into the state a [`UI`] expects, and how to put it back.
+///
+/// [`UI::new`]/[`UI::new_ticked`] call [`TerminalBackend::enter`] to
+/// construct the backend, and `destroy_app` calls
+/// [`TerminalBackend::exit`] to tear it down again. `fullscreen` is
+/// `false` for a [`Viewport::Inline`]/[`Viewport::Fixed`] [`UI`]
+/// (see `new_with_options`/`new_ticked_with_options`), in which case
+/// implementations should skip entering/leaving the alternate
+/// screen, since an inline UI renders alongside existing terminal
+/// output rather than taking over the whole screen.
+///
+/// Implement this for your own backend (termion, termwiz, an
+/// in-memory sink, ...) to use it with [`UI`]; a
+/// [`CrosstermBackend<Stdout>`] implementation is provided so [`UI`]
+/// works out of the box, and it's also what lets apps be driven
+/// headlessly against [`tui::backend::TestBackend`] by implementing
+/// this trait for it.
+pub trait TerminalBackend: Backend {
+    /// Puts the terminal into the state this backend needs (for
+    /// example, enabling raw mode, and entering the alternate screen
+    /// if `fullscreen` is `true`) and returns the constructed backend.
+    fn enter(fullscreen: bool) -> io::Result<Self>
+    where
+        Self: Sized;
+
+    /// Undoes whatever [`TerminalBackend::enter`] did, given the same
+    /// `fullscreen` value it was entered with.
+    fn exit(&mut self, fullscreen: bool) -> io::Result<()>;
+
+    /// Best-effort terminal teardown run from the panic hook installed
+    /// by `new`/`new_ticked`, given the same `fullscreen` value
+    /// `enter` was called with. Unlike [`TerminalBackend::exit`], this
+    /// can't take `&mut self`: a panic can happen with no live `UI` in
+    /// scope to call it on, so implementations must restore the
+    /// terminal some other way (e.g. by reopening `stdout`). Does
+    /// nothing by default, which is correct for backends like
+    /// [`TestBackend`] that own no real terminal to restore.
+    fn panic_restore(_fullscreen: bool) {}
+}
+
+impl TerminalBackend for CrosstermBackend<Stdout> {
+    fn enter(fullscreen: bool) -> io::Result<Self> {
+        enable_raw_mode()?;
+        let mut stdout = io::stdout();
+        execute!(stdout, EnableMouseCapture, EnableBracketedPaste)?;
+        if fullscreen {
+            execute!(stdout, EnterAlternateScreen)?;
+        }
+        Ok(CrosstermBackend::new(stdout))
+    }
+
+    fn exit(&mut self, fullscreen: bool) -> io::Result<()> {
+        disable_raw_mode()?;
+        execute!(self, DisableMouseCapture, DisableBracketedPaste)?;
+        if fullscreen {
+            execute!(self, LeaveAlternateScreen)?;
+        }
+        Ok(())
+    }
+
+    fn panic_restore(fullscreen: bool) {
+        let _ = disable_raw_mode();
+        let _ = execute!(
+            io::stdout(),
+            DisableMouseCapture,
+            DisableBracketedPaste,
+            crossterm::cursor::Show
+        );
+        if fullscreen {
+            let _ = execute!(io::stdout(), LeaveAlternateScreen);
+        }
+    }
+}
+
+impl TerminalBackend for TestBackend {
+    /// `TestBackend` has no raw mode or alternate screen to enter, so
+    /// `fullscreen` is ignored; an 80x24 backend is just a reasonable
+    /// default size for headless tests.
+    fn enter(_fullscreen: bool) -> io::Result<Self> {
+        Ok(TestBackend::new(80, 24))
+    }
+
+    /// Nothing to undo, for the same reason `enter` has nothing to do.
+    fn exit(&mut self, _fullscreen: bool) -> io::Result<()> {
+        Ok(())
+    }
+
+    // `panic_restore` is left at its no-op default: there's no real
+    // terminal behind a `TestBackend` for a panic hook to touch.
+}
 
 /// The struct containing your application and your terminal.
 ///
 /// An application is any struct which implements the [`App`]
-/// trait and (optionally) the [`Ticked`] trait. 
-/// 
-/// You should create your UI using the `new` method 
-/// (if you do not want it to be ticked), or, if you 
+/// trait and (optionally) the [`Ticked`] trait.
+///
+/// You should create your UI using the `new` method
+/// (if you do not want it to be ticked), or, if you
 /// have an application struct which implements Ticked,
-/// you should use `new_ticked` which allows you to 
+/// you should use `new_ticked` which allows you to
 /// supply a tickrate for your application.
 ///
-/// You run these UIs using their respective `run` and 
+/// You run these UIs using their respective `run` and
 /// `run_ticked` methods, depending on what kind of app
 /// you supplied. Your code will panic if you use the
 /// wrong run method for your UI (i.e. `new_ticked` and
 /// `run` used together or vice versa).
-/// 
+///
+/// [`UI`] is generic over the [`tui`] [`Backend`] it draws to, via
+/// the [`TerminalBackend`] trait, and defaults to
+/// [`CrosstermBackend<Stdout>`] wherever the type is written out, as in
+/// `let ui: UI<MyApp> = UI::new(app)?;`. A bare `UI::new(app)` call
+/// does not fall back to that default: the backend must be named,
+/// either through such a type annotation or with a turbofish. Pick a
+/// different backend (for example [`tui::backend::TestBackend`] in
+/// tests) with `UI::<MyApp, TestBackend>::new`.
+///
+/// Only construct one [`UI`] at a time: its panic hook restores
+/// whatever hook was installed before it, so overlapping the
+/// lifetimes of two `UI`s and tearing the first down before the
+/// second is undefined in spirit, if not in safety (see
+/// `install_panic_hook`).
+///
 /// # Examples
 ///
 /// This example creates an app that immediately closes after running.
@@ -35,14 +152,14 @@ use crate::app::{App, Ticked};
 /// we have assigned the open/closed state of the program to
 /// is set to true immediately. The main loop of a UI only runs while
 /// `is_closed` is returning false.
-/// 
+///
 /// TODO: Update the example to be functional in some way rather than
 /// immediately closing.
 /// ```
 /// use tui_wrapper::{ui::UI, app::App};
 /// use tui::{backend::Backend, Frame};
 /// use crossterm::event::Event;
-/// 
+///
 /// struct MyApp(bool);
 /// impl App for MyApp {
 ///     #[allow(unused)]
@@ -50,62 +167,235 @@ use crate::app::{App, Ticked};
 ///         // Write tui-rs code for drawing to screen
 ///         self.0 = true;
 ///     }
-/// 
+///
 ///     #[allow(unused)]
 ///     fn on_input_received(&mut self, event: Event) {
 ///         // Write logic for when an event is received
 ///     }
-///         
+///
 ///     fn is_closed(&self) -> bool {
 ///         // Return what indicates if the UI should close.
 ///         // In this case, is it just the first
 ///         // field of our tuple-struct
-///         self.0 
+///         self.0
 ///     }
 /// }
-///     
+///
 /// let app = MyApp(false);
 /// let mut ui = UI::new(app).unwrap();
 /// ui.run().expect("There was an error running the app");
 /// ui.destroy_app().expect("Setting the terminal back to normal encountered an error!");
 /// ```
-pub struct UI<A>
+pub struct UI<A, B = CrosstermBackend<Stdout>>
 where
     A: App,
+    B: TerminalBackend,
 {
     /// The [`tui`] Terminal interface which is passed into
     /// crossterm commands.
-    terminal: Terminal<CrosstermBackend<Stdout>>,
+    terminal: Terminal<B>,
     /// How often your application struct's `on_tick` method is called.
     tick_rate: Option<Duration>,
+    /// How often your application struct's `draw` method is called.
+    /// Only set for UIs created with `new_ticked`; UIs made with `new`
+    /// redraw on every loop iteration instead.
+    frame_rate: Option<Duration>,
+    /// The panic hook that was installed before this [`UI`] replaced it
+    /// with one that restores the terminal first. Kept around so
+    /// `destroy_app` can put it back.
+    original_hook: Option<Arc<dyn Fn(&PanicInfo) + Sync + Send + 'static>>,
+    /// Components registered with [`UI::add_component`]. Each is
+    /// drawn every frame after the app itself, and receives every
+    /// input event and every [`Action`] fed back through the queue.
+    components: Vec<Box<dyn Component<B>>>,
+    /// Set when any component returns or is fed [`Action::Quit`],
+    /// causing the run loop to stop alongside `app.is_closed()`.
+    quit_requested: bool,
+    /// Set when any component returns or is fed [`Action::Render`],
+    /// forcing `run_ticked`/`run_ticked_async` to redraw on their next
+    /// iteration even if `frame_rate` hasn't elapsed yet.
+    render_requested: bool,
+    /// Set once teardown has run, so [`Drop`] doesn't repeat it if
+    /// `destroy_app` was already called explicitly.
+    torn_down: bool,
+    /// Whether this [`UI`] entered the alternate screen, i.e. whether
+    /// its [`Viewport`] is [`Viewport::Fullscreen`]. Teardown only
+    /// leaves the alternate screen when this is `true`.
+    fullscreen: bool,
     /// See [`App`] and [`Ticked`].
     app: A,
 }
 
-impl<A: App> UI<A> {
+/// Installs a panic hook which restores the terminal (via
+/// [`TerminalBackend::panic_restore`]) before chaining to whatever
+/// hook was previously installed, and returns that previous hook so
+/// it can be restored later.
+///
+/// Only one [`UI`] should be live at a time: if a second `UI` is
+/// constructed before the first's `destroy_app`/[`Drop`] runs, the
+/// first's teardown restores the hook it captured here, discarding
+/// the second `UI`'s hook (and its panic safety net) even if the
+/// second `UI` is still alive. `UI` is a wrapper around driving a
+/// single terminal application, not a stack of them, so this crate
+/// doesn't try to support that; nest or sequence `UI`s instead of
+/// overlapping their lifetimes.
+fn install_panic_hook<B: TerminalBackend>(
+    fullscreen: bool,
+) -> Arc<dyn Fn(&PanicInfo) + Sync + Send + 'static> {
+    let original_hook: Arc<dyn Fn(&PanicInfo) + Sync + Send + 'static> =
+        Arc::from(panic::take_hook());
+    let hook_for_panic = Arc::clone(&original_hook);
+    panic::set_hook(Box::new(move |panic_info| {
+        B::panic_restore(fullscreen);
+        hook_for_panic(panic_info);
+    }));
+    original_hook
+}
+
+/// The events sent over a [`UI`]'s internal channel while it is
+/// driven by `run_ticked_async`, merging terminal input with the
+/// tick and render timers so neither has to block the other.
+#[cfg(feature = "async")]
+enum AsyncEvent {
+    /// A key, mouse, resize or paste event read from the terminal.
+    Input(CEvent),
+    /// It's time to call `on_tick`.
+    Tick,
+    /// It's time to call `draw`.
+    Render,
+}
+
+/// Spawns a task that merges a [`crossterm::event::EventStream`]
+/// with `tick_rate`/`frame_rate` interval timers onto a single
+/// channel of [`AsyncEvent`]s, so `run_ticked_async` can `recv` from
+/// one place instead of juggling both a stream and two timers.
+#[cfg(feature = "async")]
+fn spawn_async_events(
+    tick_rate: Duration,
+    frame_rate: Duration,
+) -> mpsc::UnboundedReceiver<AsyncEvent> {
+    let (tx, rx) = mpsc::unbounded_channel();
+    tokio::spawn(async move {
+        let mut reader = EventStream::new();
+        let mut tick_timer = tokio::time::interval(tick_rate);
+        let mut render_timer = tokio::time::interval(frame_rate);
+        loop {
+            let event = tokio::select! {
+                maybe_event = reader.next() => match maybe_event {
+                    Some(Ok(event)) => AsyncEvent::Input(event),
+                    Some(Err(_)) | None => break,
+                },
+                _ = tick_timer.tick() => AsyncEvent::Tick,
+                _ = render_timer.tick() => AsyncEvent::Render,
+            };
+            if tx.send(event).is_err() {
+                break;
+            }
+        }
+    });
+    rx
+}
+
+impl<A: App, B: TerminalBackend> UI<A, B> {
     /// This function creates a new [`UI`] instance, taking in a
     /// struct which implements [`App`].
-    /// 
-    /// It initializes the terminal by entering an alternate
-    /// screen, and enabling mouse capture. This function should
-    /// not be used with an application struct which also 
+    ///
+    /// It initializes the terminal by calling [`TerminalBackend::enter`]
+    /// on `B`, which for the default [`CrosstermBackend<Stdout>`] means
+    /// entering an alternate screen and enabling mouse capture. This
+    /// function should not be used with an application struct which also
     /// implements [`Ticked`], in which case the function
     /// `new_ticked` should be used instead.
     pub fn new(app: A) -> io::Result<Self> {
-        enable_raw_mode()?;
-        let mut stdout = io::stdout();
-        execute!(stdout, EnableMouseCapture, EnterAlternateScreen)?;
-        let backend = CrosstermBackend::new(stdout);
-        let terminal = Terminal::new(backend)?;
+        Self::new_with_options(app, Viewport::Fullscreen)
+    }
+
+    /// Like `new`, but lets you pick the [`Viewport`] the [`UI`]
+    /// renders into instead of always taking over the whole screen.
+    ///
+    /// [`Viewport::Fullscreen`] behaves exactly like `new`.
+    /// [`Viewport::Inline`]/[`Viewport::Fixed`] render a few lines (or
+    /// a fixed area) without entering the alternate screen, which is
+    /// useful for progress UIs or REPL-style tools that want to
+    /// render inline beneath existing shell output.
+    pub fn new_with_options(app: A, viewport: Viewport) -> io::Result<Self> {
+        let fullscreen = matches!(viewport, Viewport::Fullscreen);
+        let backend = B::enter(fullscreen)?;
+        let terminal = Terminal::with_options(backend, TerminalOptions { viewport })?;
+        let original_hook = install_panic_hook::<B>(fullscreen);
         Ok(UI {
             terminal,
             tick_rate: None,
+            frame_rate: None,
+            original_hook: Some(original_hook),
+            components: Vec::new(),
+            quit_requested: false,
+            render_requested: false,
+            torn_down: false,
+            fullscreen,
             app,
         })
     }
 
+    /// Registers a [`Component`] with this [`UI`]. Components are
+    /// drawn every frame after the app itself (in registration
+    /// order), and receive every input event and every [`Action`]
+    /// fed back through the queue.
+    pub fn add_component(&mut self, component: Box<dyn Component<B>>) {
+        self.components.push(component);
+    }
+
+    /// Feeds `action` (and every further [`Action`] it and other
+    /// components in turn return) into every registered component's
+    /// `update`, and notes a requested quit or render so the run loop
+    /// can act on them.
+    pub(crate) fn dispatch_action(&mut self, action: Action) {
+        let mut queue = vec![action];
+        while let Some(action) = queue.pop() {
+            match action {
+                Action::Quit => self.quit_requested = true,
+                Action::Render => self.render_requested = true,
+                Action::Custom(_) => {}
+            }
+            for component in self.components.iter_mut() {
+                if let Some(next) = component.update(action.clone()) {
+                    queue.push(next);
+                }
+            }
+        }
+    }
+
+    /// Draws the app and every registered component to `self.terminal`
+    /// in one frame, clearing any pending [`Action::Render`] request.
+    fn draw_frame(&mut self) -> io::Result<()> {
+        self.terminal.draw(|f| {
+            self.app.draw(f);
+            let area = f.size();
+            for component in self.components.iter_mut() {
+                component.draw(f, area);
+            }
+        })?;
+        self.render_requested = false;
+        Ok(())
+    }
+
+    /// Returns whether an [`Action::Render`] is currently pending a
+    /// redraw. Exposed for tests exercising the component/[`Action`]
+    /// system without driving a full run loop.
+    #[cfg(test)]
+    pub(crate) fn render_requested(&self) -> bool {
+        self.render_requested
+    }
+
+    /// Returns a reference to the wrapped app. Exposed for tests that
+    /// need to inspect app state after a run loop returns.
+    #[cfg(test)]
+    pub(crate) fn app(&self) -> &A {
+        &self.app
+    }
+
     /// This function runs an application that has been created
-    /// using `new`, and will panic if used with a UI made with 
+    /// using `new`, and will panic if used with a UI made with
     /// `new_ticked`.
     pub fn run(&mut self) -> io::Result<()> {
         if let Some(_) = self.tick_rate {
@@ -114,87 +404,237 @@ impl<A: App> UI<A> {
             panic!("`new`/`new_ticked` not used with respective `run`/`run_ticked` pair");
         }
 
-        while !self.app.is_closed() {
-            self.terminal.draw(|f| self.app.draw(f))?;
+        while !self.app.is_closed() && !self.quit_requested {
+            self.draw_frame()?;
             let event = event::read()?;
-            self.app.on_input_received(event);
+            self.app.on_input_received(event.clone());
+            let actions: Vec<Action> = self
+                .components
+                .iter_mut()
+                .filter_map(|component| component.handle_event(event.clone()))
+                .collect();
+            for action in actions {
+                self.dispatch_action(action);
+            }
         }
         Ok(())
     }
-    
-    /// This function leaves the alternate screen of the terminal
-    /// and disables mouse capturing events. Use this after your
+
+    /// The `async` counterpart to `run`, gated behind the `async`
+    /// cargo feature. Reads input from a [`crossterm::event::EventStream`]
+    /// instead of blocking on [`event::read`], so apps that `.await`
+    /// other futures (network calls, background tasks) between
+    /// redraws stay responsive. Will panic if used with a UI made
+    /// with `new_ticked`.
+    #[cfg(feature = "async")]
+    pub async fn run_async(&mut self) -> io::Result<()> {
+        if let Some(_) = self.tick_rate {
+            eprintln!("Hey! You shouldn't use `run_async` in conjunction with `new_ticked`. Use the functions");
+            eprintln!("in their respective pairs, which are: `new` + `run_async`, and `new_ticked` + `run_ticked_async`.");
+            panic!("`new`/`new_ticked` not used with respective `run_async`/`run_ticked_async` pair");
+        }
+
+        let mut reader = EventStream::new();
+        while !self.app.is_closed() && !self.quit_requested {
+            self.draw_frame()?;
+            if let Some(event) = reader.next().await {
+                let event = event?;
+                self.app.on_input_received(event.clone());
+                let actions: Vec<Action> = self
+                    .components
+                    .iter_mut()
+                    .filter_map(|component| component.handle_event(event.clone()))
+                    .collect();
+                for action in actions {
+                    self.dispatch_action(action);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// This function undoes whatever `B::enter` did (leaving the
+    /// alternate screen, disabling raw mode and mouse capture, ...)
+    /// by calling [`TerminalBackend::exit`]. Use this after your
     /// app's main loop is completed.
-    /// 
-    /// Keep in mind that this function can be used for both 
+    ///
+    /// You no longer have to call this yourself: `UI` also tears
+    /// itself down in [`Drop`] if you don't, so the terminal is
+    /// restored even if you return early or a caller drops the `UI`
+    /// without calling this. Calling it explicitly is still useful
+    /// when you want teardown to happen before the `UI` itself goes
+    /// out of scope.
+    ///
+    /// Keep in mind that this function can be used for both
     /// [`Ticked`] and not [`Ticked`] application structs,
-    /// unlike the `new`/`run` pairs which have variants for 
+    /// unlike the `new`/`run` pairs which have variants for
     /// application structs which are [`Ticked`].
     pub fn destroy_app(&mut self) -> io::Result<()> {
-        disable_raw_mode()?;
-        execute!(
-            self.terminal.backend_mut(),
-            DisableMouseCapture,
-            LeaveAlternateScreen
-        )?;
+        if self.torn_down {
+            return Ok(());
+        }
+        self.torn_down = true;
+        self.terminal.backend_mut().exit(self.fullscreen)?;
         self.terminal.show_cursor()?;
+        if let Some(original_hook) = self.original_hook.take() {
+            panic::set_hook(Box::new(move |panic_info| original_hook(panic_info)));
+        }
         Ok(())
     }
 }
 
-impl<A: App + Ticked> UI<A> {
-    /// This function creates a new UI, taking a tick rate
-    /// value (the time between each `on_tick` function's
-    /// calling), and an app struct which implements [`App`]
-    /// and [`Ticked`].
-    /// 
-    /// It initializes the terminal by entering an alternate
-    /// screen, and enabling mouse capture. This function 
-    /// should not be used with an application struct that
-    /// does not implement [`Ticked`], in which case the 
+impl<A: App, B: TerminalBackend> Drop for UI<A, B> {
+    /// Restores the terminal if `destroy_app` wasn't already called
+    /// explicitly, so a panicking or early-returning app can never
+    /// leave the user's terminal in a broken state.
+    fn drop(&mut self) {
+        if self.torn_down {
+            return;
+        }
+        self.torn_down = true;
+        let _ = self.terminal.backend_mut().exit(self.fullscreen);
+        let _ = self.terminal.show_cursor();
+        if let Some(original_hook) = self.original_hook.take() {
+            panic::set_hook(Box::new(move |panic_info| original_hook(panic_info)));
+        }
+    }
+}
+
+impl<A: App + Ticked, B: TerminalBackend> UI<A, B> {
+    /// This function creates a new UI, taking a tick rate value (the
+    /// time between each `on_tick` function's calling), a frame rate
+    /// value (the time between each `draw` function's calling), and
+    /// an app struct which implements [`App`] and [`Ticked`].
+    ///
+    /// Decoupling the frame rate from the tick rate means an app can
+    /// tick its simulation slowly while still rendering smoothly, or
+    /// vice versa.
+    ///
+    /// It initializes the terminal by calling [`TerminalBackend::enter`]
+    /// on `B`. This function should not be used with an application
+    /// struct that does not implement [`Ticked`], in which case the
     /// function `new` should be used instead.
-    pub fn new_ticked(app: A, tick_rate: Duration) -> io::Result<Self> {
-        enable_raw_mode()?;
-        let mut stdout = io::stdout();
-        execute!(stdout, EnableMouseCapture, EnterAlternateScreen)?;
-        let backend = CrosstermBackend::new(stdout);
-        let terminal = Terminal::new(backend)?;
+    pub fn new_ticked(app: A, tick_rate: Duration, frame_rate: Duration) -> io::Result<Self> {
+        Self::new_ticked_with_options(app, tick_rate, frame_rate, Viewport::Fullscreen)
+    }
+
+    /// Like `new_ticked`, but lets you pick the [`Viewport`] the
+    /// [`UI`] renders into instead of always taking over the whole
+    /// screen. See `new_with_options` for why you'd want to.
+    pub fn new_ticked_with_options(
+        app: A,
+        tick_rate: Duration,
+        frame_rate: Duration,
+        viewport: Viewport,
+    ) -> io::Result<Self> {
+        let fullscreen = matches!(viewport, Viewport::Fullscreen);
+        let backend = B::enter(fullscreen)?;
+        let terminal = Terminal::with_options(backend, TerminalOptions { viewport })?;
+        let original_hook = install_panic_hook::<B>(fullscreen);
         Ok(UI {
             terminal,
             tick_rate: Some(tick_rate),
+            frame_rate: Some(frame_rate),
+            original_hook: Some(original_hook),
+            components: Vec::new(),
+            quit_requested: false,
+            render_requested: false,
+            torn_down: false,
+            fullscreen,
             app,
         })
     }
 
     /// This function runs an application that has been created
-    /// using `new_ticked`, and will panic if used with a UI made with 
+    /// using `new_ticked`, and will panic if used with a UI made with
     /// `new`.
     pub fn run_ticked(&mut self) -> io::Result<()> {
-        let tr = match self.tick_rate {
+        let tick_rate = match self.tick_rate {
             None => {
                 eprintln!("Hey! You shouldn't use `run_ticked` in conjunction with `new`. Use the functions");
                 eprintln!("in their respective pairs, which are: `new` + `run`, and `new_ticked` + `run_ticked`.");
                 panic!("`new`/`new_ticked` not used with respective `run`/`run_ticked` pair");
             }
-            Some(tr) => tr,
+            Some(tick_rate) => tick_rate,
         };
-        
+        let frame_rate = self.frame_rate.unwrap();
+
+        self.draw_frame()?;
+
         let mut last_tick = Instant::now();
-        while !self.app.is_closed() {
-            self.terminal.draw(|f| self.app.draw(f))?;
-            let timeout = tr
-                .checked_sub(last_tick.elapsed())
-                .unwrap_or(Duration::from_secs(0));
+        let mut last_frame = Instant::now();
+        while !self.app.is_closed() && !self.quit_requested {
+            let timeout = tick_rate
+                .saturating_sub(last_tick.elapsed())
+                .min(frame_rate.saturating_sub(last_frame.elapsed()));
 
             if event::poll(timeout)? {
                 let event = event::read()?;
-                self.app.on_input_received(event);
+                self.app.on_input_received(event.clone());
+                let actions: Vec<Action> = self
+                    .components
+                    .iter_mut()
+                    .filter_map(|component| component.handle_event(event.clone()))
+                    .collect();
+                for action in actions {
+                    self.dispatch_action(action);
+                }
             }
-            if last_tick.elapsed() >= self.tick_rate.unwrap() {
+            if last_tick.elapsed() >= tick_rate {
                 self.app.on_tick();
                 last_tick = Instant::now();
             }
+            if last_frame.elapsed() >= frame_rate || self.render_requested {
+                self.draw_frame()?;
+                last_frame = Instant::now();
+            }
+        }
+        Ok(())
+    }
+
+    /// The `async` counterpart to `run_ticked`, gated behind the
+    /// `async` cargo feature. A background task merges a
+    /// [`crossterm::event::EventStream`] with the tick and frame
+    /// timers onto one channel (see [`spawn_async_events`]), which
+    /// this function drains, dispatching input to
+    /// `on_input_received`, ticks to `on_tick`, and renders to
+    /// `draw`. Will panic if used with a UI made with `new`.
+    #[cfg(feature = "async")]
+    pub async fn run_ticked_async(&mut self) -> io::Result<()> {
+        let tick_rate = match self.tick_rate {
+            None => {
+                eprintln!("Hey! You shouldn't use `run_ticked_async` in conjunction with `new`. Use the functions");
+                eprintln!("in their respective pairs, which are: `new` + `run_async`, and `new_ticked` + `run_ticked_async`.");
+                panic!("`new`/`new_ticked` not used with respective `run_async`/`run_ticked_async` pair");
+            }
+            Some(tick_rate) => tick_rate,
+        };
+        let frame_rate = self.frame_rate.unwrap();
+
+        self.draw_frame()?;
+
+        let mut events = spawn_async_events(tick_rate, frame_rate);
+        while !self.app.is_closed() && !self.quit_requested {
+            match events.recv().await {
+                Some(AsyncEvent::Input(event)) => {
+                    self.app.on_input_received(event.clone());
+                    let actions: Vec<Action> = self
+                        .components
+                        .iter_mut()
+                        .filter_map(|component| component.handle_event(event.clone()))
+                        .collect();
+                    for action in actions {
+                        self.dispatch_action(action);
+                    }
+                    if self.render_requested {
+                        self.draw_frame()?;
+                    }
+                }
+                Some(AsyncEvent::Tick) => self.app.on_tick(),
+                Some(AsyncEvent::Render) => self.draw_frame()?,
+                None => break,
+            }
         }
         Ok(())
     }
-}
\ No newline at end of file
+}